@@ -1,8 +1,14 @@
-use std::sync::RwLock;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use reqwest::StatusCode;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use tokio::sync::broadcast;
+use tracing::{debug, error, trace, warn};
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
@@ -28,6 +34,20 @@ enum Method {
     TorrentAdd,
     #[serde(rename = "torrent-get")]
     TorrentGet,
+    #[serde(rename = "torrent-start")]
+    TorrentStart,
+    #[serde(rename = "torrent-start-now")]
+    TorrentStartNow,
+    #[serde(rename = "torrent-stop")]
+    TorrentStop,
+    #[serde(rename = "torrent-remove")]
+    TorrentRemove,
+    #[serde(rename = "torrent-verify")]
+    TorrentVerify,
+    #[serde(rename = "torrent-reannounce")]
+    TorrentReannounce,
+    #[serde(rename = "torrent-set")]
+    TorrentSet,
 }
 
 #[derive(Serialize)]
@@ -36,20 +56,149 @@ enum Args {
     SessionGet(SessionGetArgs),
     TorrentAdd(TorrentAddArgs),
     TorrentGet(TorrentGetArgs),
+    TorrentAction(TorrentActionArgs),
+    TorrentRemove(TorrentRemoveArgs),
+    TorrentSet(TorrentSetArgs),
+}
+
+/// A single entry of a mixed `ids` selector: either a numeric torrent id
+/// or a 40-char hex hashString. `#[serde(untagged)]` picks whichever shape
+/// matches the value at serialization time.
+#[derive(Serialize, Clone)]
+#[serde(untagged)]
+pub enum IdOrHash {
+    Id(u32),
+    Hash(String),
+}
+
+/// The `ids` selector accepted by `torrent-get` and the torrent mutation
+/// methods. The RPC spec allows numeric ids, 40-char hex hashStrings, a
+/// mix of both, the literal string `"recently-active"`, or omitting the
+/// field entirely to mean "all torrents".
+///
+/// `TorrentIds::All` is the default and serializes as an absent field
+/// (see `is_all`/`skip_serializing_if` on the structs that embed it).
+#[derive(Clone, Default)]
+pub enum TorrentIds {
+    #[default]
+    All,
+    Ids(Vec<u32>),
+    Hashes(Vec<String>),
+    Mixed(Vec<IdOrHash>),
+    RecentlyActive,
+}
+
+impl TorrentIds {
+    fn is_all(&self) -> bool {
+        matches!(self, TorrentIds::All)
+    }
+}
+
+impl Serialize for TorrentIds {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            TorrentIds::All => serializer.serialize_none(),
+            TorrentIds::Ids(ids) => ids.serialize(serializer),
+            TorrentIds::Hashes(hashes) => hashes.serialize(serializer),
+            TorrentIds::Mixed(mixed) => mixed.serialize(serializer),
+            TorrentIds::RecentlyActive => "recently-active".serialize(serializer),
+        }
+    }
+}
+
+impl From<Vec<u32>> for TorrentIds {
+    fn from(ids: Vec<u32>) -> Self {
+        TorrentIds::Ids(ids)
+    }
+}
+
+impl From<Vec<String>> for TorrentIds {
+    fn from(hashes: Vec<String>) -> Self {
+        TorrentIds::Hashes(hashes)
+    }
+}
+
+impl From<Vec<IdOrHash>> for TorrentIds {
+    fn from(mixed: Vec<IdOrHash>) -> Self {
+        TorrentIds::Mixed(mixed)
+    }
+}
+
+// TODO complete rest of mutable fields (seed ratio/idle limits, location, etc)
+#[skip_serializing_none]
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct TorrentSetArgs {
+    #[serde(skip_serializing_if = "TorrentIds::is_all")]
+    pub ids: TorrentIds,
+    pub labels: Option<Vec<String>>,
+    pub bandwidth_priority: Option<i32>, // -1, 0, 1 for LOW MEDIUM HIGH priority torrent
+    pub files_wanted: Option<Vec<u32>>,
+    pub files_unwanted: Option<Vec<u32>>,
+    pub priority_high: Option<Vec<u32>>,
+    pub priority_low: Option<Vec<u32>>,
+    pub priority_normal: Option<Vec<u32>>,
+    pub peer_limit: Option<u32>,
+    pub download_dir: Option<String>,
+}
+
+/// Argument shape shared by the torrent actions that only need an `ids`
+/// selector: `torrent-start`, `torrent-start-now`, `torrent-stop`,
+/// `torrent-verify` and `torrent-reannounce`.
+#[derive(Serialize, Default)]
+pub struct TorrentActionArgs {
+    #[serde(skip_serializing_if = "TorrentIds::is_all")]
+    pub ids: TorrentIds,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct TorrentRemoveArgs {
+    #[serde(skip_serializing_if = "TorrentIds::is_all")]
+    pub ids: TorrentIds,
+    pub delete_local_data: bool,
+}
+
+/// Marker response for RPC methods that reply with an empty `arguments`
+/// object (e.g. the torrent action methods below).
+#[derive(Deserialize, Serialize)]
+pub struct Empty {}
+
+impl ResponseArgs for Empty {}
+
+/// Whether `torrent-get` should reply with one JSON object per torrent
+/// (the default) or with a `[header, row, row, ...]` array-of-arrays,
+/// which is considerably smaller on the wire when polling many torrents.
+#[derive(Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TorrentGetFormat {
+    #[default]
+    Objects,
+    Table,
+}
+
+impl TorrentGetFormat {
+    fn is_objects(&self) -> bool {
+        *self == TorrentGetFormat::Objects
+    }
 }
 
-// TODO: "format" argument
-// TODO: "ids" can also be strings (hashes, 'recently-active' etc) check spec
 #[skip_serializing_none]
 #[derive(Serialize, Default)]
 pub struct TorrentGetArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     fields: Option<Vec<TorrentGetFields>>,
-    ids: Option<Vec<u32>>,
+    #[serde(skip_serializing_if = "TorrentIds::is_all")]
+    ids: TorrentIds,
+    #[serde(skip_serializing_if = "TorrentGetFormat::is_objects")]
+    format: TorrentGetFormat,
 }
 
 // TODO complete rest of fields
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub enum TorrentGetFields {
     Error,
@@ -70,16 +219,66 @@ pub enum TorrentGetFields {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 pub struct TorrentGet {
     torrents: Vec<Torrent>,
-    removed: Option<Vec<Torrent>>,
+    // The spec returns `removed` as a bare array of torrent ids, not objects.
+    removed: Option<Vec<i64>>,
 }
 
 impl ResponseArgs for TorrentGet {}
 
+// `torrent-get` replies with `torrents` as an array of objects by default,
+// but as a `[header, row, row, ...]` array-of-arrays when `format: "table"`
+// was requested. Detect which shape came back and, for the table case, zip
+// the header row's field names onto each positional row before handing it
+// to `Torrent`'s normal deserializer, so callers get `Vec<Torrent>` either way.
+impl<'de> Deserialize<'de> for TorrentGet {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            torrents: serde_json::Value,
+            removed: Option<Vec<i64>>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let torrents = match raw.torrents {
+            serde_json::Value::Array(rows)
+                if matches!(rows.first(), Some(serde_json::Value::Array(_))) =>
+            {
+                table_rows_to_torrents(rows).map_err(serde::de::Error::custom)?
+            }
+            other => serde_json::from_value(other).map_err(serde::de::Error::custom)?,
+        };
+        Ok(TorrentGet {
+            torrents,
+            removed: raw.removed,
+        })
+    }
+}
+
+fn table_rows_to_torrents(rows: Vec<serde_json::Value>) -> std::result::Result<Vec<Torrent>, String> {
+    let mut rows = rows.into_iter();
+    let header: Vec<String> = rows
+        .next()
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default();
+    rows.map(|row| {
+        let values: Vec<serde_json::Value> = serde_json::from_value(row).map_err(|e| e.to_string())?;
+        let object: serde_json::Map<String, serde_json::Value> =
+            header.iter().cloned().zip(values).collect();
+        serde_json::from_value(serde_json::Value::Object(object)).map_err(|e| e.to_string())
+    })
+    .collect()
+}
+
 #[skip_serializing_none]
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Torrent {
     error: Option<u32>,
@@ -98,8 +297,11 @@ pub struct Torrent {
     total_size: Option<i32>,
 }
 
-// TODO: (from spec) Either filename or metainfo must be included. All other arguments are optional  (OR just let user decide)
 // TODO: cookies are supposed to have a particular format, maybe enforce through types/serde? or just let user provide in string format
+//
+// Either `filename` or `metainfo` must be set, never both — prefer
+// `Client::torrent_add_file`/`Client::torrent_add_magnet` with
+// `TorrentAddOptions` over constructing this directly.
 #[skip_serializing_none]
 #[derive(Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -120,6 +322,45 @@ pub struct TorrentAddArgs {
     pub priority_normal: Option<Vec<u32>>,
 }
 
+/// Options shared by [`Client::torrent_add_file`] and
+/// [`Client::torrent_add_magnet`] — everything `TorrentAddArgs` accepts
+/// except `filename`/`metainfo`, which each constructor fills in for its
+/// own add path so the two can't be mixed up.
+#[derive(Default)]
+pub struct TorrentAddOptions {
+    pub cookies: Option<String>,
+    pub download_dir: Option<String>,
+    pub labels: Option<String>,
+    pub paused: Option<String>,
+    pub peer_limit: Option<u32>,
+    pub bandwidth_priority: Option<u32>,
+    pub files_wanted: Option<Vec<u32>>,
+    pub files_unwanted: Option<Vec<u32>>,
+    pub priority_high: Option<Vec<u32>>,
+    pub priority_low: Option<Vec<u32>>,
+    pub priority_normal: Option<Vec<u32>>,
+}
+
+impl TorrentAddOptions {
+    fn into_args(self, filename: Option<String>, metainfo: Option<String>) -> TorrentAddArgs {
+        TorrentAddArgs {
+            cookies: self.cookies,
+            download_dir: self.download_dir,
+            filename,
+            labels: self.labels,
+            metainfo,
+            paused: self.paused,
+            peer_limit: self.peer_limit,
+            bandwidth_priority: self.bandwidth_priority,
+            files_wanted: self.files_wanted,
+            files_unwanted: self.files_unwanted,
+            priority_high: self.priority_high,
+            priority_low: self.priority_low,
+            priority_normal: self.priority_normal,
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -153,10 +394,122 @@ pub struct SessionGet {
 }
 impl ResponseArgs for SessionGet {}
 
+// Exponential backoff with jitter, capped at `max`. Jitter shaves off up to
+// half the delay so that concurrent clients don't retry in lockstep.
+fn backoff_delay(base: Duration, attempt: u32, max: Duration) -> Duration {
+    let exponential = base
+        .checked_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX))
+        .unwrap_or(max)
+        .min(max);
+    let jitter_range = exponential.as_nanos() as u64 / 2 + 1;
+    exponential.saturating_sub(Duration::from_nanos(jitter_seed() % jitter_range))
+}
+
+fn jitter_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos() as u64
+}
+
+const DEFAULT_USER_AGENT: &str = concat!("arta/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_RETRIES: u8 = 5;
+const DEFAULT_BACKOFF_BASE: Duration = Duration::from_millis(250);
+const DEFAULT_BACKOFF_MAX: Duration = Duration::from_secs(10);
+
+/// Builds a [`Client`] from a Transmission daemon's `host`, `port` and
+/// scheme, with optional HTTP Basic credentials for daemons running with
+/// `rpc-authentication-required` (or behind a reverse proxy that enforces
+/// its own auth).
+pub struct ClientBuilder {
+    host: String,
+    port: u16,
+    tls: bool,
+    auth: Option<(String, String)>,
+    retries: u8,
+    backoff_base: Duration,
+    backoff_max: Duration,
+}
+
+impl ClientBuilder {
+    pub fn new(host: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            port: 9091,
+            tls: false,
+            auth: None,
+            retries: DEFAULT_RETRIES,
+            backoff_base: DEFAULT_BACKOFF_BASE,
+            backoff_max: DEFAULT_BACKOFF_MAX,
+        }
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    pub fn tls(mut self, tls: bool) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    pub fn auth(mut self, user: impl Into<String>, pass: impl Into<String>) -> Self {
+        self.auth = Some((user.into(), pass.into()));
+        self
+    }
+
+    /// Maximum number of requests `request` sends before giving up; after
+    /// each `409 Conflict` it refreshes the session id and tries again.
+    /// Defaults to 5.
+    pub fn retries(mut self, retries: u8) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Base delay for the exponential backoff between retries (`base * 2^attempt`).
+    pub fn backoff_base(mut self, backoff_base: Duration) -> Self {
+        self.backoff_base = backoff_base;
+        self
+    }
+
+    /// Upper bound on the backoff delay, regardless of attempt count.
+    pub fn backoff_max(mut self, backoff_max: Duration) -> Self {
+        self.backoff_max = backoff_max;
+        self
+    }
+
+    pub fn build(self) -> Result<Client> {
+        let scheme = if self.tls { "https" } else { "http" };
+        let url = format!(
+            "{scheme}://{}:{}/transmission/rpc",
+            self.host, self.port
+        );
+        let http_client = reqwest::Client::builder()
+            .user_agent(DEFAULT_USER_AGENT)
+            .build()?;
+        Ok(Client {
+            url,
+            session_id: None.into(),
+            http_client,
+            auth: self.auth,
+            retries: self.retries,
+            backoff_base: self.backoff_base,
+            backoff_max: self.backoff_max,
+            watcher: None.into(),
+        })
+    }
+}
+
 pub struct Client {
     url: String,
     session_id: RwLock<Option<String>>,
     http_client: reqwest::Client,
+    auth: Option<(String, String)>,
+    retries: u8,
+    backoff_base: Duration,
+    backoff_max: Duration,
+    watcher: RwLock<Option<Arc<Watcher>>>,
 }
 
 impl Client {
@@ -164,43 +517,61 @@ impl Client {
         Self {
             url: url.to_owned(),
             session_id: None.into(),
-            http_client: reqwest::Client::new(),
+            http_client: reqwest::Client::builder()
+                .user_agent(DEFAULT_USER_AGENT)
+                .build()
+                .unwrap_or_default(),
+            auth: None,
+            retries: DEFAULT_RETRIES,
+            backoff_base: DEFAULT_BACKOFF_BASE,
+            backoff_max: DEFAULT_BACKOFF_MAX,
+            watcher: None.into(),
         }
     }
 
+    pub fn builder(host: impl Into<String>) -> ClientBuilder {
+        ClientBuilder::new(host)
+    }
+
     async fn request<T: ResponseArgs + DeserializeOwned>(
         &self,
         method: Method,
         arguments: Option<Args>,
     ) -> Result<TransmissionResponse<T>> {
         let data = TransmissionRequest { method, arguments };
-        // change to logging (tracing crate?)
-        println!("Sending request: {}", serde_json::to_string(&data).unwrap());
+        let request_json = serde_json::to_string(&data).unwrap();
+        debug!(request = %request_json, "sending transmission RPC request");
 
-        const RETRIES: u8 = 5;
-        for _retry in 0..RETRIES {
+        for attempt in 0..self.retries {
             let mut request = self.http_client.post(&self.url);
             if let Some(session_id) = self.session_id.read().unwrap().as_deref() {
                 request = request.header("X-Transmission-Session-id", session_id);
             }
+            if let Some((user, pass)) = &self.auth {
+                request = request.basic_auth(user, Some(pass));
+            }
             request = request.json(&data);
             let response = request.send().await?;
-            println!("status code = {}", response.status());
+            trace!(status = %response.status(), "received transmission RPC response");
             if response.status() == StatusCode::CONFLICT {
+                warn!("session id rejected with 409, refreshing and retrying");
                 *self.session_id.write().unwrap() = Some(
                     response.headers()["X-Transmission-Session-id"]
                         .to_str()
                         .unwrap()
                         .to_owned(),
                 );
+                let delay = backoff_delay(self.backoff_base, attempt as u32, self.backoff_max);
+                tokio::time::sleep(delay).await;
                 continue;
             }
             let deserialized_response: TransmissionResponse<T> = response.json().await?;
             return Ok(deserialized_response);
         }
+        error!(retries = self.retries, "exhausted retries sending request to transmission server");
         Err(format!(
             "Failed after {} retries to send request to transmission server",
-            RETRIES
+            self.retries
         )
         .into())
     }
@@ -224,6 +595,30 @@ impl Client {
             .await
     }
 
+    /// Read a local `.torrent` file and add it by base64-encoding its
+    /// contents into `metainfo`, so callers don't have to encode it
+    /// themselves.
+    pub async fn torrent_add_file(
+        &self,
+        path: impl AsRef<Path>,
+        opts: TorrentAddOptions,
+    ) -> Result<TransmissionResponse<TorrentAdd>> {
+        let bytes = tokio::fs::read(path.as_ref()).await?;
+        let metainfo = BASE64.encode(bytes);
+        self.torrent_add(opts.into_args(None, Some(metainfo))).await
+    }
+
+    /// Add a torrent from a magnet link or a remote `.torrent` URL, routed
+    /// through `filename` as the spec expects.
+    pub async fn torrent_add_magnet(
+        &self,
+        uri: impl Into<String>,
+        opts: TorrentAddOptions,
+    ) -> Result<TransmissionResponse<TorrentAdd>> {
+        self.torrent_add(opts.into_args(Some(uri.into()), None))
+            .await
+    }
+
     pub async fn torrent_get(
         &self,
         args: TorrentGetArgs,
@@ -231,6 +626,218 @@ impl Client {
         self.request(Method::TorrentGet, Some(Args::TorrentGet(args)))
             .await
     }
+
+    pub async fn torrent_start(
+        &self,
+        ids: impl Into<TorrentIds>,
+    ) -> Result<TransmissionResponse<Empty>> {
+        self.request(
+            Method::TorrentStart,
+            Some(Args::TorrentAction(TorrentActionArgs { ids: ids.into() })),
+        )
+        .await
+    }
+
+    pub async fn torrent_start_now(
+        &self,
+        ids: impl Into<TorrentIds>,
+    ) -> Result<TransmissionResponse<Empty>> {
+        self.request(
+            Method::TorrentStartNow,
+            Some(Args::TorrentAction(TorrentActionArgs { ids: ids.into() })),
+        )
+        .await
+    }
+
+    pub async fn torrent_stop(
+        &self,
+        ids: impl Into<TorrentIds>,
+    ) -> Result<TransmissionResponse<Empty>> {
+        self.request(
+            Method::TorrentStop,
+            Some(Args::TorrentAction(TorrentActionArgs { ids: ids.into() })),
+        )
+        .await
+    }
+
+    pub async fn torrent_verify(
+        &self,
+        ids: impl Into<TorrentIds>,
+    ) -> Result<TransmissionResponse<Empty>> {
+        self.request(
+            Method::TorrentVerify,
+            Some(Args::TorrentAction(TorrentActionArgs { ids: ids.into() })),
+        )
+        .await
+    }
+
+    pub async fn torrent_reannounce(
+        &self,
+        ids: impl Into<TorrentIds>,
+    ) -> Result<TransmissionResponse<Empty>> {
+        self.request(
+            Method::TorrentReannounce,
+            Some(Args::TorrentAction(TorrentActionArgs { ids: ids.into() })),
+        )
+        .await
+    }
+
+    pub async fn torrent_remove(
+        &self,
+        ids: impl Into<TorrentIds>,
+        delete_local_data: bool,
+    ) -> Result<TransmissionResponse<Empty>> {
+        self.request(
+            Method::TorrentRemove,
+            Some(Args::TorrentRemove(TorrentRemoveArgs {
+                ids: ids.into(),
+                delete_local_data,
+            })),
+        )
+        .await
+    }
+
+    pub async fn torrent_set(&self, args: TorrentSetArgs) -> Result<TransmissionResponse<Empty>> {
+        self.request(Method::TorrentSet, Some(Args::TorrentSet(args)))
+            .await
+    }
+
+    /// Start a background task that polls `torrent-get` with
+    /// `ids: recently-active` every `interval` and emits a [`TorrentEvent`]
+    /// for every change it sees. Takes `&Arc<Client>` because the poll loop
+    /// outlives the call and needs its own handle on the client.
+    ///
+    /// Calling this more than once on the same client reuses the existing
+    /// poll loop — every caller gets an independent receiver off the same
+    /// broadcast channel instead of spawning a second poller. The poll loop
+    /// stops itself once every receiver has been dropped; calling `watch`
+    /// again afterwards starts a fresh one.
+    ///
+    /// `Id`, `Status` and `PercentDone` are always requested in addition to
+    /// `fields`, since the diffing in [`Watcher::apply`] needs them to emit
+    /// `Added`/`StatusChanged`/`Completed` at all.
+    pub fn watch(
+        self: &Arc<Self>,
+        interval: Duration,
+        fields: Option<Vec<TorrentGetFields>>,
+    ) -> broadcast::Receiver<TorrentEvent> {
+        if let Some(watcher) = self.watcher.read().unwrap().as_ref() {
+            return watcher.sender.subscribe();
+        }
+
+        let mut slot = self.watcher.write().unwrap();
+        if let Some(watcher) = slot.as_ref() {
+            return watcher.sender.subscribe();
+        }
+
+        let (sender, receiver) = broadcast::channel(WATCH_CHANNEL_CAPACITY);
+        let watcher = Arc::new(Watcher {
+            sender,
+            snapshot: RwLock::new(HashMap::new()),
+        });
+        *slot = Some(Arc::clone(&watcher));
+        drop(slot);
+
+        let fields = with_required_watch_fields(fields);
+        let client = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                // Check-and-clear must happen under the same write-lock
+                // critical section `watch()`'s fast path gates on, or a
+                // subscriber can slip in between the receiver-count check
+                // and clearing the slot and be handed a receiver for a
+                // channel nobody is polling anymore.
+                let mut slot = client.watcher.write().unwrap();
+                if watcher.sender.receiver_count() == 0 {
+                    debug!("torrent watch has no subscribers left, stopping poll loop");
+                    *slot = None;
+                    break;
+                }
+                drop(slot);
+
+                let args = TorrentGetArgs {
+                    fields: Some(fields.clone()),
+                    ids: TorrentIds::RecentlyActive,
+                    ..Default::default()
+                };
+                match client.torrent_get(args).await {
+                    Ok(response) => watcher.apply(response.arguments),
+                    Err(e) => warn!(error = %e, "torrent watch poll failed"),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        });
+
+        receiver
+    }
+}
+
+const WATCH_CHANNEL_CAPACITY: usize = 256;
+
+/// Fields [`Watcher::apply`] needs on every `Torrent` to diff responses,
+/// merged into whatever the caller asked for so a reduced-bandwidth
+/// `fields` list passed to [`Client::watch`] can't silently break it.
+fn with_required_watch_fields(fields: Option<Vec<TorrentGetFields>>) -> Vec<TorrentGetFields> {
+    let mut fields = fields.unwrap_or_default();
+    for required in [
+        TorrentGetFields::Id,
+        TorrentGetFields::Status,
+        TorrentGetFields::PercentDone,
+    ] {
+        if !fields.contains(&required) {
+            fields.push(required);
+        }
+    }
+    fields
+}
+
+/// A state change observed by [`Client::watch`].
+#[derive(Debug, Clone)]
+pub enum TorrentEvent {
+    Added { id: i32 },
+    Removed { id: i32 },
+    StatusChanged { id: i32, from: i32, to: i32 },
+    Completed { id: i32 },
+}
+
+/// Shared state for a running [`Client::watch`] poll loop: the broadcast
+/// channel subscribers attach to, and the last-seen snapshot used to diff
+/// each new `torrent-get` response.
+struct Watcher {
+    sender: broadcast::Sender<TorrentEvent>,
+    snapshot: RwLock<HashMap<i32, Torrent>>,
+}
+
+impl Watcher {
+    fn apply(&self, response: TorrentGet) {
+        let mut snapshot = self.snapshot.write().unwrap();
+        for torrent in response.torrents {
+            let Some(id) = torrent.id else { continue };
+            match snapshot.get(&id) {
+                None => {
+                    let _ = self.sender.send(TorrentEvent::Added { id });
+                }
+                Some(previous) => {
+                    if let (Some(from), Some(to)) = (previous.status, torrent.status) {
+                        if from != to {
+                            let _ = self.sender.send(TorrentEvent::StatusChanged { id, from, to });
+                        }
+                    }
+                    let was_done = previous.percent_done.unwrap_or(0.0) >= 1.0;
+                    let is_done = torrent.percent_done.unwrap_or(0.0) >= 1.0;
+                    if !was_done && is_done {
+                        let _ = self.sender.send(TorrentEvent::Completed { id });
+                    }
+                }
+            }
+            snapshot.insert(id, torrent);
+        }
+        for id in response.removed.into_iter().flatten() {
+            let id = id as i32;
+            snapshot.remove(&id);
+            let _ = self.sender.send(TorrentEvent::Removed { id });
+        }
+    }
 }
 
 #[cfg(test)]
@@ -275,6 +882,46 @@ mod tests {
         };
     }
 
+    #[tokio::test]
+    async fn test_torrent_add_magnet() {
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Client::new(&url);
+        let arch_iso_magnet = "magnet:?xt=urn:btih:ab6ad7ff24b5ed3a61352a1f1a7811a8c3cc6dde&dn=archlinux-2023.09.01-x86_64.iso".to_owned();
+        let res = trans_client
+            .torrent_add_magnet(arch_iso_magnet, TorrentAddOptions::default())
+            .await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+    }
+
+    #[tokio::test]
+    async fn test_torrent_add_file() {
+        let path = std::env::temp_dir().join("arta_test_torrent_add_file.torrent");
+        tokio::fs::write(&path, b"d8:announce0:4:infod6:lengthi0e4:name0:12:piece lengthi0e6:pieces0ee")
+            .await
+            .unwrap();
+
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Client::new(&url);
+        let res = trans_client
+            .torrent_add_file(&path, TorrentAddOptions::default())
+            .await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+    }
+
     #[tokio::test]
     async fn test_torrent_get() {
         let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
@@ -298,4 +945,266 @@ mod tests {
             }
         };
     }
+
+    #[test]
+    fn test_torrent_get_table_format_decodes_into_torrents() {
+        let payload = serde_json::json!({
+            "result": "success",
+            "arguments": {
+                "torrents": [
+                    ["id", "name", "percentDone"],
+                    [1, "archlinux-2023.09.01-x86_64.iso", 1.0],
+                    [2, "debian-12.1.0-amd64-netinst.iso", 0.5]
+                ]
+            }
+        });
+        let res: TransmissionResponse<TorrentGet> = serde_json::from_value(payload).unwrap();
+        assert_eq!(res.arguments.torrents.len(), 2);
+        assert_eq!(res.arguments.torrents[0].id, Some(1));
+        assert_eq!(
+            res.arguments.torrents[1].name.as_deref(),
+            Some("debian-12.1.0-amd64-netinst.iso")
+        );
+    }
+
+    #[test]
+    fn test_watcher_diffs_status_and_completion() {
+        let (sender, mut receiver) = broadcast::channel(WATCH_CHANNEL_CAPACITY);
+        let watcher = Watcher {
+            sender,
+            snapshot: RwLock::new(HashMap::new()),
+        };
+
+        let torrent = |id: i32, status: i32, percent_done: f32| -> Torrent {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "status": status,
+                "percentDone": percent_done,
+            }))
+            .unwrap()
+        };
+
+        watcher.apply(TorrentGet {
+            torrents: vec![torrent(1, 4, 0.5)],
+            removed: None,
+        });
+        assert!(matches!(
+            receiver.try_recv().unwrap(),
+            TorrentEvent::Added { id: 1 }
+        ));
+
+        watcher.apply(TorrentGet {
+            torrents: vec![torrent(1, 6, 1.0)],
+            removed: None,
+        });
+        assert!(matches!(
+            receiver.try_recv().unwrap(),
+            TorrentEvent::StatusChanged {
+                id: 1,
+                from: 4,
+                to: 6
+            }
+        ));
+        assert!(matches!(
+            receiver.try_recv().unwrap(),
+            TorrentEvent::Completed { id: 1 }
+        ));
+
+        watcher.apply(TorrentGet {
+            torrents: vec![],
+            removed: Some(vec![1]),
+        });
+        assert!(matches!(
+            receiver.try_recv().unwrap(),
+            TorrentEvent::Removed { id: 1 }
+        ));
+    }
+
+    #[test]
+    fn test_torrent_get_removed_deserializes_as_bare_ids() {
+        let payload = serde_json::json!({
+            "result": "success",
+            "arguments": {
+                "torrents": [],
+                "removed": [1, 2, 3]
+            }
+        });
+        let res: TransmissionResponse<TorrentGet> = serde_json::from_value(payload).unwrap();
+        assert_eq!(res.arguments.removed, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_torrent_ids_wire_format() {
+        assert_eq!(
+            serde_json::to_value(TorrentIds::All).unwrap(),
+            serde_json::Value::Null
+        );
+        assert_eq!(
+            serde_json::to_value(TorrentIds::RecentlyActive).unwrap(),
+            serde_json::json!("recently-active")
+        );
+        assert_eq!(
+            serde_json::to_value(TorrentIds::Ids(vec![1, 2, 3])).unwrap(),
+            serde_json::json!([1, 2, 3])
+        );
+        assert_eq!(
+            serde_json::to_value(TorrentIds::Hashes(vec!["abc123".to_owned()])).unwrap(),
+            serde_json::json!(["abc123"])
+        );
+        assert_eq!(
+            serde_json::to_value(TorrentIds::Mixed(vec![
+                IdOrHash::Id(1),
+                IdOrHash::Hash("abc123".to_owned()),
+            ]))
+            .unwrap(),
+            serde_json::json!([1, "abc123"])
+        );
+
+        #[derive(Serialize)]
+        struct HasIds {
+            #[serde(skip_serializing_if = "TorrentIds::is_all")]
+            ids: TorrentIds,
+        }
+        assert_eq!(
+            serde_json::to_value(HasIds {
+                ids: TorrentIds::All
+            })
+            .unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn test_with_required_watch_fields_merges_and_dedups() {
+        let fields = with_required_watch_fields(Some(vec![
+            TorrentGetFields::Name,
+            TorrentGetFields::Status,
+        ]));
+        assert_eq!(
+            fields,
+            vec![
+                TorrentGetFields::Name,
+                TorrentGetFields::Status,
+                TorrentGetFields::Id,
+                TorrentGetFields::PercentDone,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_stops_poll_loop_once_receivers_drop() {
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Arc::new(Client::new(&url));
+        let receiver = trans_client.watch(Duration::from_millis(20), None);
+        assert!(trans_client.watcher.read().unwrap().is_some());
+
+        drop(receiver);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(
+            trans_client.watcher.read().unwrap().is_none(),
+            "poll loop should stop and clear the watcher slot once its last receiver is dropped"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_torrent_get_table_format() {
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Client::new(&url);
+        let res = trans_client
+            .torrent_get(TorrentGetArgs {
+                fields: Some(vec![TorrentGetFields::Id, TorrentGetFields::Name]),
+                format: TorrentGetFormat::Table,
+                ..Default::default()
+            })
+            .await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+    }
+
+    #[tokio::test]
+    async fn test_torrent_get_recently_active() {
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Client::new(&url);
+        let res = trans_client
+            .torrent_get(TorrentGetArgs {
+                fields: Some(vec![TorrentGetFields::Id]),
+                ids: TorrentIds::RecentlyActive,
+                ..Default::default()
+            })
+            .await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+    }
+
+    #[tokio::test]
+    async fn test_torrent_stop_and_start() {
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Client::new(&url);
+        let res = trans_client.torrent_stop(vec![1]).await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+        let res = trans_client.torrent_start(vec![1]).await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+    }
+
+    #[tokio::test]
+    async fn test_torrent_remove() {
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Client::new(&url);
+        let res = trans_client.torrent_remove(vec![1], false).await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+    }
+
+    #[tokio::test]
+    async fn test_torrent_set() {
+        let url = "http://127.0.0.1:9091/transmission/rpc".to_owned();
+        let trans_client = Client::new(&url);
+        let res = trans_client
+            .torrent_set(TorrentSetArgs {
+                ids: vec![1].into(),
+                labels: Some(vec!["linux-isos".to_owned()]),
+                ..Default::default()
+            })
+            .await;
+        match res {
+            Ok(res) => {
+                println!("Got response: {}", serde_json::to_string(&res).unwrap());
+            }
+            Err(e) => {
+                dbg!(e);
+            }
+        };
+    }
 }